@@ -70,14 +70,164 @@
 //! You can keep a circle buffer entirely on the stack using [CircleBuffer::new]:
 //! ```
 //! # use jcirclebuffer::CircleBuffer;
-//! CircleBuffer::new([0; 4]); // Does not require feature "std"
+//! CircleBuffer::new([0u8; 4]); // Does not require feature "std"
 //! ```
 
+/// Maximum number of out-of-order holes the reassembler tracks before
+/// [CircleBuffer::fill_at] returns [AssemblerError::TooManyHoles].
+const CONTIG_COUNT: usize = 8;
+
+/// Returned by [CircleBuffer::fill_at] when an out-of-order segment cannot be tracked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssemblerError {
+    /// Recording the segment would need more absent/present spans than [CONTIG_COUNT].
+    TooManyHoles,
+}
+
+/// An alternating absent/present span, mirroring smoltcp's `Contig`: a run of `hole_size`
+/// missing elements immediately followed by `data_size` present ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Contig {
+    hole_size: usize,
+    data_size: usize,
+}
+
+impl Contig {
+    fn total_size(&self) -> usize {
+        self.hole_size + self.data_size
+    }
+
+    fn is_empty(&self) -> bool {
+        self.total_size() == 0
+    }
+}
+
+/// Tracks which elements ahead of the committed front have been filled out of order. Offsets
+/// are relative to the committed front (i.e. relative to `len`); the list stays sorted and its
+/// spans never overlap. A fully-contiguous front collapses to a single leading data contig.
+struct Assembler {
+    contigs: [Contig; CONTIG_COUNT],
+}
+
+impl Assembler {
+    const fn new() -> Assembler {
+        Assembler {
+            contigs: [Contig {
+                hole_size: 0,
+                data_size: 0,
+            }; CONTIG_COUNT],
+        }
+    }
+
+    /// True when no spans are tracked at all. The list is kept compact, so an empty leading
+    /// contig means the whole list is empty.
+    fn is_empty(&self) -> bool {
+        self.contigs[0].is_empty()
+    }
+
+    /// Size of the leading fully-present run, or zero if a hole sits at the front.
+    fn front_data(&self) -> usize {
+        if self.contigs[0].hole_size == 0 {
+            self.contigs[0].data_size
+        } else {
+            0
+        }
+    }
+
+    /// Remove and return the leading fully-present run, shifting the remaining contigs down.
+    fn remove_front(&mut self) -> usize {
+        let advance = self.front_data();
+        if advance == 0 {
+            return 0;
+        }
+        self.contigs.copy_within(1.., 0);
+        self.contigs[CONTIG_COUNT - 1] = Contig::default();
+        advance
+    }
+
+    /// Splice the present span `[offset, offset + size)` into the list, merging it with any
+    /// adjacent present spans. Returns an error if the result needs more than [CONTIG_COUNT] spans.
+    fn add(&mut self, offset: usize, size: usize) -> Result<(), AssemblerError> {
+        if size == 0 {
+            return Ok(());
+        }
+        // Materialize the present intervals, splice the new one in, merge, then rebuild.
+        let mut intervals = [(0usize, 0usize); CONTIG_COUNT + 1];
+        let mut count = 0;
+        let mut pos = 0;
+        for contig in &self.contigs {
+            if contig.is_empty() {
+                break;
+            }
+            pos += contig.hole_size;
+            if contig.data_size != 0 {
+                intervals[count] = (pos, pos + contig.data_size);
+                count += 1;
+            }
+            pos += contig.data_size;
+        }
+
+        let mut insert_at = count;
+        for (i, iv) in intervals.iter().enumerate().take(count) {
+            if offset < iv.0 {
+                insert_at = i;
+                break;
+            }
+        }
+        let mut i = count;
+        while i > insert_at {
+            intervals[i] = intervals[i - 1];
+            i -= 1;
+        }
+        intervals[insert_at] = (offset, offset + size);
+        count += 1;
+
+        let mut merged = 0;
+        for read in 1..count {
+            let (s, e) = intervals[read];
+            if s <= intervals[merged].1 {
+                if e > intervals[merged].1 {
+                    intervals[merged].1 = e;
+                }
+            } else {
+                merged += 1;
+                intervals[merged] = (s, e);
+            }
+        }
+        let merged_count = merged + 1;
+
+        if merged_count > CONTIG_COUNT {
+            return Err(AssemblerError::TooManyHoles);
+        }
+        let mut contigs = [Contig::default(); CONTIG_COUNT];
+        let mut prev_end = 0;
+        for (idx, contig) in contigs.iter_mut().enumerate().take(merged_count) {
+            let (s, e) = intervals[idx];
+            *contig = Contig {
+                hole_size: s - prev_end,
+                data_size: e - s,
+            };
+            prev_end = e;
+        }
+        self.contigs = contigs;
+        Ok(())
+    }
+}
+
 /// A circle buffer based on an unmoving underlying buffer.
-pub struct CircleBuffer<T> {
+///
+/// `E` is the element type stored in the ring; it defaults to `u8` so the common byte-buffer
+/// case stays `CircleBuffer<Vec<u8>>`. It is carried as a [core::marker::PhantomData] field
+/// because a `T: AsRef<[E]>` bound alone cannot pin `E` (a type may implement both
+/// `AsRef<[u8]>` and `AsRef<[u32]>`).
+pub struct CircleBuffer<T, E = u8> {
     start: usize,
     len: usize,
     buf: T,
+    asm: Assembler,
+    growable: bool,
+    init: usize,
+    _marker: core::marker::PhantomData<E>,
 }
 
 #[cfg(feature = "std")]
@@ -97,20 +247,152 @@ impl CircleBuffer<Vec<u8>> {
             start: 0,
             len: 0,
             buf,
+            asm: Assembler::new(),
+            growable: false,
+            init: size,
+            _marker: core::marker::PhantomData,
         }
     }
+
+    /// A heap allocated circle buffer whose backing store is allowed to grow. Starts at
+    /// `initial` bytes; growth happens explicitly through [CircleBuffer::reserve], which
+    /// reallocates to a larger, re-linearized backing store. Requires "std".
+    ///
+    /// Growth is reserve-driven rather than automatic: the [std::io::Write] impl is generic over
+    /// the backing and cannot reallocate a concrete [std::vec::Vec] from inside `write`, so
+    /// [std::io::Write::write] still returns `Err("Full")` on a full buffer. Call `reserve` for
+    /// the bytes you are about to write first — unlike a fixed buffer, a growable one can always
+    /// satisfy it.
+    /// ```
+    /// # use jcirclebuffer::CircleBuffer;
+    /// use std::io::Write;
+    /// let mut buf = CircleBuffer::growable(4);
+    /// buf.write_all(b"abcd").unwrap();
+    /// buf.reserve(4); // makes room; write_all would otherwise return Err "Full"
+    /// buf.write_all(b"efgh").unwrap();
+    /// assert_eq!(buf.view_nocopy(), b"abcdefgh");
+    /// ```
+    pub fn growable(initial: usize) -> Self {
+        let mut buffer = CircleBuffer::with_size(initial);
+        buffer.growable = true;
+        buffer
+    }
+
+    /// Ensure room for at least `additional` more bytes. On a growable buffer this reallocates
+    /// to a larger backing store when needed, "unwrapping" the ring so `start` becomes 0 again;
+    /// otherwise it is a no-op while capacity suffices. Panics if growth is needed on a
+    /// non-growable buffer.
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.len.checked_add(additional).unwrap();
+        if needed <= self.size() {
+            return;
+        }
+        assert!(self.growable, "reserve beyond capacity on a non-growable CircleBuffer");
+        let new_size = core::cmp::max(needed, self.size().checked_mul(2).unwrap());
+        self.relinearize(new_size);
+    }
+
+    /// Move the committed data to the front of a freshly sized backing store, copying the head
+    /// span then the tail span so the ring is unwrapped and `start` resets to 0.
+    fn relinearize(&mut self, new_size: usize) {
+        let mut new_buf = vec![0; new_size];
+        let (head, tail) = self.view_parts(self.len);
+        new_buf[..head.len()].copy_from_slice(head);
+        new_buf[head.len()..self.len].copy_from_slice(tail);
+        self.buf = new_buf;
+        self.start = 0;
+        self.init = new_size;
+    }
 }
 
-impl<T> CircleBuffer<T>
+#[cfg(feature = "std")]
+impl CircleBuffer<Vec<core::mem::MaybeUninit<u8>>, core::mem::MaybeUninit<u8>> {
+    /// Like [CircleBuffer::with_size] but leaves the backing store uninitialized instead of
+    /// zeroing it, avoiding a potentially large memset that the first read would immediately
+    /// overwrite. Following std's `BorrowedBuf`/`BorrowedCursor` design, bytes become readable
+    /// only once [CircleBuffer::read_buf] writes them. Requires "std".
+    pub fn with_size_uninit(size: usize) -> Self {
+        let mut buf = Vec::with_capacity(size);
+        // SAFETY: `MaybeUninit<u8>` is a valid value in any bit pattern, so extending the length
+        // over uninitialized capacity is sound. The crate tracks the initialized watermark in
+        // `init` and never exposes an element before `read_buf` initializes and commits it.
+        unsafe { buf.set_len(size) };
+        CircleBuffer {
+            start: 0,
+            len: 0,
+            buf,
+            asm: Assembler::new(),
+            growable: false,
+            init: 0,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// A `read_buf`-style fill that never zeroes the backing store. Hands the leading contiguous
+    /// writable region to `fill` along with how many of its bytes are already initialized from a
+    /// prior fill, and commits however many bytes `fill` reports it initialized at the front.
+    /// Returns the number of bytes committed, or 0 if the buffer is full.
+    pub fn read_buf<F>(&mut self, fill: F) -> usize
+    where
+        F: FnOnce(&mut [core::mem::MaybeUninit<u8>], usize) -> usize,
+    {
+        let end = (self.start + self.len) % self.size();
+        let already = self.init.saturating_sub(end);
+        let region = match self.get_fillable_area() {
+            Some(region) => region,
+            None => return 0,
+        };
+        let region_len = region.len();
+        let already = core::cmp::min(already, region_len);
+        let amt = fill(region, already);
+        assert!(amt <= region_len);
+        self.fill(amt);
+        let reached = end + amt;
+        if reached > self.init {
+            self.init = reached;
+        }
+        amt
+    }
+
+    /// The leading contiguous run of committed, initialized data as bytes. Never crosses the
+    /// wrap point. This is the initialized-only companion to [CircleBuffer::view_nocopy], which
+    /// for this backing yields [core::mem::MaybeUninit] elements.
+    pub fn filled(&self) -> &[u8] {
+        let data = self.view_nocopy();
+        // SAFETY: every element in `[start, start + len)` was written by `read_buf` before being
+        // committed with `fill`, so it is initialized.
+        unsafe { &*(data as *const [core::mem::MaybeUninit<u8>] as *const [u8]) }
+    }
+
+    /// Like [CircleBuffer::view_parts] but yielding initialized bytes, split at the wrap point.
+    pub fn filled_parts(&self, amt: usize) -> (&[u8], &[u8]) {
+        let (head, tail) = self.view_parts(amt);
+        // SAFETY: see [CircleBuffer::filled]; both spans lie within the committed region.
+        unsafe {
+            (
+                &*(head as *const [core::mem::MaybeUninit<u8>] as *const [u8]),
+                &*(tail as *const [core::mem::MaybeUninit<u8>] as *const [u8]),
+            )
+        }
+    }
+}
+
+impl<T, E> CircleBuffer<T, E>
 where
-    T: AsRef<[u8]> + AsMut<[u8]>,
+    T: AsRef<[E]> + AsMut<[E]>,
+    E: Copy,
 {
     /// Make a circle buffer backed by a user-provided buffer. This can be used to make a stack allocated circle buffer.
-    pub fn new(buf: T) -> CircleBuffer<T> {
+    pub fn new(buf: T) -> CircleBuffer<T, E> {
+        let init = buf.as_ref().len();
         CircleBuffer {
             start: 0,
             len: 0,
             buf,
+            asm: Assembler::new(),
+            growable: false,
+            init,
+            _marker: core::marker::PhantomData,
         }
     }
 
@@ -134,23 +416,8 @@ where
         assert!(self.len <= self.size());
     }
 
-    #[cfg(feature = "std")]
-    /// A convenience wrapper around get_fillable_area() -> Read::read() -> buf.fill(amt).
-    /// Doesn't fill() if Read::read returns an error.
-    pub fn read<U>(&mut self, reader: &mut U) -> std::io::Result<usize>
-    where
-        U: std::io::Read,
-    {
-        let read_zone = self.get_fillable_area().expect("read buffer full");
-        let result = std::io::Read::read(reader, read_zone);
-        if let Ok(amt) = result {
-            self.fill(amt);
-        }
-        result
-    }
-
     /// Copy data into the circle buffer, possibly crossing the wrap point. Does fill() automatically. Panics if capacity is not available.
-    pub fn extend(&mut self, data: &[u8]) {
+    pub fn extend(&mut self, data: &[E]) {
         let head = self.get_fillable_area().unwrap();
         let head_amt = core::cmp::min(data.len(), head.len());
         head[..head_amt].copy_from_slice(&data[..head_amt]);
@@ -173,6 +440,51 @@ where
     pub fn clear(&mut self) {
         self.len = 0;
         self.start = 0;
+        self.asm = Assembler::new();
+    }
+
+    /// Copy `data` in at logical `offset` from the front, allowing out-of-order fills ahead of
+    /// the contiguous front. The bytes are written into the backing store at
+    /// `(start + offset) % size`, splitting across the wrap point like [CircleBuffer::extend],
+    /// and the range is spliced into the hole-tracking assembler. [CircleBuffer::len] only
+    /// advances over the leading fully-present run, so [CircleBuffer::view_nocopy] never exposes
+    /// an unfilled hole. Returns an error if too many holes would need tracking.
+    ///
+    /// Panics if the written range would overrun the backing store.
+    pub fn fill_at(&mut self, offset: usize, data: &[E]) -> Result<(), AssemblerError> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let end = offset.checked_add(data.len()).unwrap();
+        assert!(end <= self.size());
+        // Splice into the assembler before touching the backing store. `add` rejects a segment
+        // that would need too many holes without mutating its state, so a rejected fill leaves the
+        // buffer untouched. Only the part ahead of the committed front is tracked; anything
+        // overlapping already-committed data is just an in-place overwrite.
+        if end > self.len {
+            let asm_start = core::cmp::max(offset, self.len);
+            self.asm.add(asm_start - self.len, end - asm_start)?;
+        }
+        let size = self.size();
+        let write_start = self.start.checked_add(offset).unwrap() % size;
+        let first = core::cmp::min(data.len(), size - write_start);
+        self.buf.as_mut()[write_start..write_start + first].copy_from_slice(&data[..first]);
+        if first < data.len() {
+            let rest = data.len() - first;
+            self.buf.as_mut()[..rest].copy_from_slice(&data[first..]);
+        }
+        self.commit();
+        Ok(())
+    }
+
+    /// Advance the committed [CircleBuffer::len] over the leading fully-present run tracked by
+    /// the assembler. Called automatically by [CircleBuffer::fill_at]; a no-op when no
+    /// out-of-order data is waiting in front.
+    pub fn commit(&mut self) {
+        let advance = self.asm.remove_front();
+        if advance != 0 {
+            self.fill(advance);
+        }
     }
 
     /// The total amount of free space available for filling.
@@ -190,23 +502,35 @@ where
         self.len == self.size()
     }
 
-    #[cfg(feature = "std")]
-    /// Allows a contiguous view of potentially non-contiguous underlying data. MAY INCUR A COPY. Should only incur copies rarely if the size of the buffer is large relative to the possible message size. Requires feature "std".
-    pub fn view<R>(&self, amt: usize, callback: impl FnOnce(&[u8]) -> R) -> R {
-        let (head, tail) = self.view_parts(amt);
-        if tail.is_empty() {
-            return callback(head);
+    /// Push a single element onto the back of the buffer. Returns `Err(item)` if the buffer
+    /// is full. This is the single-element companion to [CircleBuffer::extend] for non-byte
+    /// element types.
+    pub fn enqueue(&mut self, item: E) -> Result<(), E> {
+        match self.get_fillable_area() {
+            Some(area) => {
+                area[0] = item;
+                self.fill(1);
+                Ok(())
+            }
+            None => Err(item),
         }
-        let mut view_buffer = vec![0; head.len() + tail.len()];
-        view_buffer[..head.len()].copy_from_slice(head);
-        view_buffer[head.len()..].copy_from_slice(tail);
-        callback(&view_buffer)
+    }
+
+    /// Pop a single element off the front of the buffer. Returns `None` if the buffer is empty.
+    /// This is the single-element companion to [CircleBuffer::consume] for non-byte element types.
+    pub fn dequeue(&mut self) -> Option<E> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.view_nocopy()[0];
+        self.consume(1);
+        Some(item)
     }
 
     /// Allows a contgious view of potentially non-contiguous data using a user-provided buffer. May incur a copy but will not incur a heap allocation. Available without feature "std".
-    pub fn view_provided<C, R>(&self, buf: &mut [u8], callback: C) -> R
+    pub fn view_provided<C, R>(&self, buf: &mut [E], callback: C) -> R
     where
-        C: FnOnce(&[u8]) -> R,
+        C: FnOnce(&[E]) -> R,
     {
         let amt = buf.len();
         let (head, tail) = self.view_parts(amt);
@@ -219,9 +543,9 @@ where
     }
 
     /// view_provided but mut. Changes made to the view slice will be reflected in the only the circlebuffer buffer the view did not cross a wrap point and will be reflected in only in the provided buffer if the view did cross a wrap point.
-    pub fn view_provided_mut<C, R>(&mut self, buf: &mut [u8], callback: C) -> R
+    pub fn view_provided_mut<C, R>(&mut self, buf: &mut [E], callback: C) -> R
     where
-        C: FnOnce(&mut [u8]) -> R,
+        C: FnOnce(&mut [E]) -> R,
     {
         let amt = buf.len();
         let (head, tail) = self.view_parts_mut(amt);
@@ -234,9 +558,24 @@ where
     }
 
     /// View potentially non-contiguous data. Will never incur a copy. Returns (head, tail). All the data will be in the head unless data crosses the wrap point.
-    pub fn view_parts(&self, amt: usize) -> (&[u8], &[u8]) {
-        assert!(amt <= self.len);
-        let start = self.start;
+    pub fn view_parts(&self, amt: usize) -> (&[E], &[E]) {
+        self.view_parts_at(0, amt)
+    }
+
+    /// view_parts but mutable.
+    pub fn view_parts_mut(&mut self, amt: usize) -> (&mut [E], &mut [E]) {
+        self.view_parts_at_mut(0, amt)
+    }
+
+    /// Like [CircleBuffer::view_parts] but starting `offset` elements into the buffer instead of
+    /// at the front, for random-access peeks that don't consume the data in front. The view
+    /// begins at `(start + offset) % size` and is split at the wrap point. [CircleBuffer::view]
+    /// and [CircleBuffer::view_provided] take no offset and always start at the front; there is
+    /// no offset variant of them that copies an interior window into one contiguous slice.
+    /// Asserts `offset + amt <= len`.
+    pub fn view_parts_at(&self, offset: usize, amt: usize) -> (&[E], &[E]) {
+        assert!(offset.checked_add(amt).unwrap() <= self.len);
+        let start = (self.start + offset) % self.size();
         let view_end = start.checked_add(amt).unwrap();
         if view_end <= self.size() {
             return (&self.buf.as_ref()[start..view_end], &[]);
@@ -244,13 +583,13 @@ where
         let buf = self.buf.as_ref();
         let (left, data_head) = buf.split_at(start);
         let (data_tail, _) = left.split_at(view_end % self.size());
-        return (data_head, data_tail);
+        (data_head, data_tail)
     }
 
-    /// view_parts but mutable.
-    pub fn view_parts_mut(&mut self, amt: usize) -> (&mut [u8], &mut [u8]) {
-        assert!(amt <= self.len);
-        let start = self.start;
+    /// view_parts_at but mutable.
+    pub fn view_parts_at_mut(&mut self, offset: usize, amt: usize) -> (&mut [E], &mut [E]) {
+        assert!(offset.checked_add(amt).unwrap() <= self.len);
+        let start = (self.start + offset) % self.size();
         let view_end = start.checked_add(amt).unwrap();
         if view_end <= self.size() {
             return (&mut self.buf.as_mut()[start..view_end], &mut []);
@@ -259,11 +598,11 @@ where
         let buf = self.buf.as_mut();
         let (left, data_head) = buf.split_at_mut(start);
         let (data_tail, _) = left.split_at_mut(remainder);
-        return (data_head, data_tail);
+        (data_head, data_tail)
     }
 
     /// Returns the maximum amount of meaningful contiguous data. Will never incur a copy.
-    pub fn view_nocopy(&self) -> &[u8] {
+    pub fn view_nocopy(&self) -> &[E] {
         let mut view_end = self.start.checked_add(self.len).unwrap();
         if view_end > self.size() {
             view_end = self.size();
@@ -271,19 +610,40 @@ where
         &self.buf.as_ref()[self.start..view_end]
     }
 
-    /// Marks data as consumed. Advances the "start" cursor by amt. If this results in the buffer being empty, moves the start cursor to 0. Does not touch the underlying buffer.
+    /// Marks data as consumed. Advances the "start" cursor by amt. If this empties the buffer and
+    /// no out-of-order data is waiting, moves the start cursor back to 0. Does not touch the
+    /// underlying buffer.
     pub fn consume(&mut self, amt: usize) {
         self.len = self.len.checked_sub(amt).unwrap();
-        if self.len == 0 {
+        // The assembler's offsets are relative to the physical anchor `(start + len) % size`.
+        // Consuming from the front advances `start` and shrinks `len` by the same amount, so the
+        // anchor stays put and the pending spans remain valid. The empty-buffer realignment to 0
+        // discards that anchor, so it is only safe when nothing is waiting ahead of the front.
+        if self.len == 0 && self.asm.is_empty() {
             self.start = 0;
         } else {
             self.start = self.start.checked_add(amt).unwrap() % self.size();
         }
     }
 
+    /// Zero-copy drain of the leading contiguous slice behind a single bounds check. Computes
+    /// the front slice (at most `max` elements, never crossing the wrap point) once, hands it to
+    /// `f`, and advances `start`/`len` by the number of elements `f` reports it used. This gives
+    /// parser loops a fast path that avoids re-deriving the start/len bookkeeping twice per read
+    /// the way a [CircleBuffer::view_parts] + [CircleBuffer::consume] pair would.
+    pub fn consume_with<R>(&mut self, max: usize, f: impl FnOnce(&[E]) -> (usize, R)) -> R {
+        let view_end = core::cmp::min(self.start.checked_add(self.len).unwrap(), self.size());
+        let end = core::cmp::min(view_end, self.start.saturating_add(max));
+        let n = end - self.start;
+        let (used, result) = f(&self.buf.as_ref()[self.start..end]);
+        assert!(used <= n);
+        self.consume(used);
+        result
+    }
+
     /// Returns the next contiguous unused area in the underlying buffer. Returns None if the buffer is full.
     /// There are potentially two separate contiguous unused areas in the buffer at any one time. If you use up one of them (and call fill()) then you will be able to get to the other one.
-    pub fn get_fillable_area(&mut self) -> Option<&mut [u8]> {
+    pub fn get_fillable_area(&mut self) -> Option<&mut [E]> {
         if self.len == self.size() {
             return None;
         }
@@ -298,26 +658,119 @@ where
     }
 }
 
-impl<T> std::io::Write for CircleBuffer<T>
+#[cfg(feature = "std")]
+impl<T, E> CircleBuffer<T, E>
+where
+    T: AsRef<[E]> + AsMut<[E]>,
+    E: Copy + Default,
+{
+    /// Allows a contiguous view of potentially non-contiguous underlying data. MAY INCUR A COPY. Should only incur copies rarely if the size of the buffer is large relative to the possible message size. Requires feature "std".
+    pub fn view<R>(&self, amt: usize, callback: impl FnOnce(&[E]) -> R) -> R {
+        let (head, tail) = self.view_parts(amt);
+        if tail.is_empty() {
+            return callback(head);
+        }
+        let mut view_buffer = vec![E::default(); head.len() + tail.len()];
+        view_buffer[..head.len()].copy_from_slice(head);
+        view_buffer[head.len()..].copy_from_slice(tail);
+        callback(&view_buffer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> CircleBuffer<T, u8>
+where
+    T: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// A convenience wrapper around get_fillable_area() -> Read::read() -> buf.fill(amt).
+    /// Doesn't fill() if Read::read returns an error.
+    pub fn read<U>(&mut self, reader: &mut U) -> std::io::Result<usize>
+    where
+        U: std::io::Read,
+    {
+        let read_zone = self.get_fillable_area().expect("read buffer full");
+        let result = std::io::Read::read(reader, read_zone);
+        if let Ok(amt) = result {
+            self.fill(amt);
+        }
+        result
+    }
+
+    /// Returns both contiguous fillable regions as [std::io::IoSliceMut]s, in fill order. When
+    /// the wrap point splits the free space, the first slice runs to the end of the backing
+    /// store and the second wraps back to the front; otherwise the second slice is empty. This
+    /// lets a single `readv` populate both pieces without a second round trip to the OS.
+    pub fn get_fillable_slices(&mut self) -> [std::io::IoSliceMut<'_>; 2] {
+        let size = self.size();
+        let start = self.start;
+        let raw_end = start + self.len;
+        let buf = self.buf.as_mut();
+        if raw_end < size {
+            let (left, first) = buf.split_at_mut(raw_end);
+            let (second, _occupied) = left.split_at_mut(start);
+            [
+                std::io::IoSliceMut::new(first),
+                std::io::IoSliceMut::new(second),
+            ]
+        } else {
+            let end = raw_end - size;
+            let (left, _tail) = buf.split_at_mut(start);
+            let (_head, first) = left.split_at_mut(end);
+            [
+                std::io::IoSliceMut::new(first),
+                std::io::IoSliceMut::new(&mut []),
+            ]
+        }
+    }
+
+    /// Returns the readable region for `amt` bytes as two [std::io::IoSlice]s split at the wrap
+    /// point, so a single `writev` can drain both pieces at once. All the data is in the first
+    /// slice unless it crosses the wrap point. Panics if `amt` exceeds [CircleBuffer::len].
+    pub fn view_io_slices(&self, amt: usize) -> [std::io::IoSlice<'_>; 2] {
+        let (head, tail) = self.view_parts(amt);
+        [std::io::IoSlice::new(head), std::io::IoSlice::new(tail)]
+    }
+}
+
+impl<T> std::io::Write for CircleBuffer<T, u8>
 where
     T: AsRef<[u8]> + AsMut<[u8]>,
 {
     fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
         let available = self.available();
         if available == 0 {
-            return Err(std::io::Error::new(std::io::ErrorKind::Other, "Full"));
+            return Err(std::io::Error::other("Full"));
         }
         let amt = std::cmp::min(data.len(), available);
         self.extend(&data[..amt]);
         Ok(amt)
     }
 
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        let available = self.available();
+        if available == 0 {
+            return Err(std::io::Error::other("Full"));
+        }
+        let total: usize = bufs.iter().map(|b| b.len()).sum();
+        let amt = std::cmp::min(total, available);
+        let mut remaining = amt;
+        for buf in bufs {
+            if remaining == 0 {
+                break;
+            }
+            let take = std::cmp::min(buf.len(), remaining);
+            self.extend(&buf[..take]);
+            remaining -= take;
+        }
+        Ok(amt)
+    }
+
     fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
     }
 }
 
-impl<T> std::io::Read for CircleBuffer<T>
+impl<T> std::io::Read for CircleBuffer<T, u8>
 where
     T: AsRef<[u8]> + AsMut<[u8]>,
 {
@@ -329,6 +782,32 @@ where
         self.consume(amt);
         Ok(amt)
     }
+
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
+        let total: usize = bufs.iter().map(|b| b.len()).sum();
+        let amt = std::cmp::min(self.len(), total);
+        let (head, tail) = self.view_parts(amt);
+        let mut dst = 0;
+        let mut doff = 0;
+        for src in [head, tail] {
+            let mut soff = 0;
+            while soff < src.len() {
+                while dst < bufs.len() && doff == bufs[dst].len() {
+                    dst += 1;
+                    doff = 0;
+                }
+                if dst >= bufs.len() {
+                    break;
+                }
+                let take = std::cmp::min(src.len() - soff, bufs[dst].len() - doff);
+                bufs[dst][doff..doff + take].copy_from_slice(&src[soff..soff + take]);
+                soff += take;
+                doff += take;
+            }
+        }
+        self.consume(amt);
+        Ok(amt)
+    }
 }
 
 #[cfg(test)]
@@ -378,4 +857,243 @@ mod tests {
         assert_eq!(b"cde", &read_buf);
         assert_eq!(buffer.len(), 1);
     }
+
+    #[test]
+    fn generic_element_tests() {
+        // A ring of something other than bytes.
+        let mut circle_buffer = CircleBuffer::new([0u32; 3]);
+        assert!(circle_buffer.enqueue(10).is_ok());
+        assert!(circle_buffer.enqueue(20).is_ok());
+        assert!(circle_buffer.enqueue(30).is_ok());
+        assert_eq!(circle_buffer.enqueue(40), Err(40));
+        assert_eq!(circle_buffer.view_nocopy(), &[10, 20, 30]);
+
+        assert_eq!(circle_buffer.dequeue(), Some(10));
+        assert_eq!(circle_buffer.dequeue(), Some(20));
+        // Wrap the ring and make sure a contiguous view still copies correctly.
+        circle_buffer.extend(&[40, 50]);
+        assert_eq!(circle_buffer.view_parts(3), (&[30u32][..], &[40, 50][..]));
+        circle_buffer.view(3, |data| assert_eq!(data, &[30, 40, 50]));
+
+        assert_eq!(circle_buffer.dequeue(), Some(30));
+        assert_eq!(circle_buffer.dequeue(), Some(40));
+        assert_eq!(circle_buffer.dequeue(), Some(50));
+        assert_eq!(circle_buffer.dequeue(), None);
+    }
+
+    #[test]
+    fn out_of_order_fill_tests() {
+        let mut buffer = CircleBuffer::new([0u8; 8]);
+        // A segment ahead of the front leaves a hole, so len() stays put.
+        buffer.fill_at(3, b"de").unwrap();
+        assert_eq!(buffer.len(), 0);
+        // Fill the trailing gap: still a hole in front.
+        buffer.fill_at(5, b"f").unwrap();
+        assert_eq!(buffer.len(), 0);
+        // Close the front hole; now everything up to the next hole commits at once.
+        buffer.fill_at(0, b"abc").unwrap();
+        assert_eq!(buffer.len(), 6);
+        assert_eq!(buffer.view_nocopy(), b"abcdef");
+
+        // Out-of-order fill that wraps across the end of the backing store. After the
+        // consume below, start sits at 2 with a single committed byte "z" at offset 0.
+        let mut wrap = CircleBuffer::new([0u8; 4]);
+        wrap.extend(b"xyz");
+        wrap.consume(2);
+        assert_eq!(wrap.view_nocopy(), b"z");
+        // Offsets 1 and 2 land at backing indices 3 and 0, straddling the wrap point.
+        wrap.fill_at(1, b"ab").unwrap();
+        assert_eq!(wrap.len(), 3);
+        assert_eq!(wrap.view_parts(3), (&b"za"[..], &b"b"[..]));
+        wrap.view(3, |data| assert_eq!(data, b"zab"));
+
+        // Consuming down to empty must not discard a pending out-of-order span. The single "Z"
+        // at offset 4 is waiting ahead of the front when the committed "ab" is drained away.
+        let mut pending = CircleBuffer::new([0u8; 16]);
+        pending.fill_at(0, b"ab").unwrap();
+        pending.fill_at(4, b"Z").unwrap();
+        pending.consume(2);
+        assert_eq!(pending.len(), 0);
+        // Filling the remaining gap connects with the preserved "Z" rather than a phantom zero.
+        pending.fill_at(0, b"xy").unwrap();
+        assert_eq!(pending.len(), 3);
+        assert_eq!(pending.view_nocopy(), b"xyZ");
+    }
+
+    #[test]
+    fn too_many_holes_errors() {
+        let mut buffer = CircleBuffer::new([0u8; 32]);
+        // Leave a hole before each single-byte island until the assembler runs out of spans.
+        let mut offset = 1;
+        let mut result = Ok(());
+        for _ in 0..super::CONTIG_COUNT + 2 {
+            result = buffer.fill_at(offset, b"x");
+            if result.is_err() {
+                break;
+            }
+            offset += 2;
+        }
+        assert_eq!(result, Err(super::AssemblerError::TooManyHoles));
+
+        // A rejected fill must leave the backing store and the committed length untouched.
+        let mut buffer = CircleBuffer::new([0u8; 32]);
+        let mut offset = 1;
+        loop {
+            if buffer.fill_at(offset, b"x").is_err() {
+                break;
+            }
+            offset += 2;
+        }
+        let before = buffer.buf;
+        assert_eq!(buffer.len(), 0);
+        assert!(buffer.fill_at(offset, b"y").is_err());
+        assert_eq!(buffer.len(), 0);
+        assert_eq!(buffer.buf, before);
+    }
+
+    #[test]
+    fn vectored_io_tests() {
+        use std::io::{IoSlice, IoSliceMut, Read, Write};
+        let mut buffer = CircleBuffer::new([0u8; 6]);
+        // Gather two iovecs into the buffer with one write_vectored.
+        let written = buffer
+            .write_vectored(&[IoSlice::new(b"abc"), IoSlice::new(b"def")])
+            .unwrap();
+        assert_eq!(written, 6);
+        assert_eq!(buffer.view_nocopy(), b"abcdef");
+
+        // Drain into two scattered destinations with one read_vectored.
+        let mut a = [0u8; 2];
+        let mut b = [0u8; 4];
+        let read = {
+            let mut dsts = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+            buffer.read_vectored(&mut dsts).unwrap()
+        };
+        assert_eq!(read, 6);
+        assert_eq!(&a, b"ab");
+        assert_eq!(&b, b"cdef");
+
+        // With data wrapped across the end, both the fillable and readable regions split in two.
+        buffer.extend(b"wxyz");
+        buffer.consume(3);
+        // start == 3, len == 1, so the free space is [4, 6) then wraps to [0, 3).
+        let slices = buffer.get_fillable_slices();
+        assert_eq!(slices[0].len(), 2);
+        assert_eq!(slices[1].len(), 3);
+        buffer.extend(b"123");
+        // Logical data is "z123"; physically it splits as "z12" at the tail then "3" at the front.
+        let readable = buffer.view_io_slices(4);
+        assert_eq!(&*readable[0], b"z12");
+        assert_eq!(&*readable[1], b"3");
+    }
+
+    #[test]
+    fn growable_tests() {
+        use std::io::Write;
+        let mut buf = CircleBuffer::growable(4);
+        buf.write_all(b"abcd").unwrap();
+        assert!(buf.is_full());
+        assert!(Write::write(&mut buf, b"x").is_err());
+        // Consume and wrap so the ring must be unwrapped when it grows.
+        buf.consume(2);
+        buf.extend(b"ef");
+        assert_eq!(buf.view_parts(4), (&b"cd"[..], &b"ef"[..]));
+        buf.reserve(4);
+        assert_eq!(buf.size(), 8);
+        assert_eq!(buf.view_nocopy(), b"cdef");
+        buf.write_all(b"ghij").unwrap();
+        assert_eq!(buf.view_nocopy(), b"cdefghij");
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_growable_reserve_panics() {
+        let mut buf = CircleBuffer::with_size(4);
+        buf.extend(b"abcd");
+        buf.reserve(1);
+    }
+
+    #[test]
+    fn uninit_tests() {
+        let mut buf = CircleBuffer::with_size_uninit(4);
+        assert_eq!(buf.len(), 0);
+        // A fresh region reports nothing pre-initialized.
+        let n = buf.read_buf(|region, already| {
+            assert_eq!(already, 0);
+            assert_eq!(region.len(), 4);
+            let mut written = 0;
+            for (slot, &byte) in region.iter_mut().zip(b"abc") {
+                slot.write(byte);
+                written += 1;
+            }
+            written
+        });
+        assert_eq!(n, 3);
+        assert_eq!(buf.filled(), b"abc");
+
+        buf.consume(1);
+        // Contiguous fillable piece is [3, 4); still uninitialized there.
+        let n = buf.read_buf(|region, already| {
+            assert_eq!(already, 0);
+            assert_eq!(region.len(), 1);
+            region[0].write(b'd');
+            1
+        });
+        assert_eq!(n, 1);
+        assert_eq!(buf.filled(), b"bcd");
+
+        // Wrap: the next piece is [0, 1), which a prior fill already initialized.
+        let n = buf.read_buf(|region, already| {
+            assert_eq!(already, 1);
+            region[0].write(b'e');
+            1
+        });
+        assert_eq!(n, 1);
+        assert_eq!(buf.filled_parts(4), (&b"bcd"[..], &b"e"[..]));
+    }
+
+    #[test]
+    fn view_parts_at_tests() {
+        let mut buf = CircleBuffer::new([0u8; 4]);
+        buf.extend(b"abcd");
+        buf.consume(1);
+        buf.extend(b"e"); // wrapped layout; logical data is "bcde"
+        assert_eq!(buf.view_parts_at(0, 4), (&b"bcd"[..], &b"e"[..]));
+        // An interior window that straddles the wrap point.
+        assert_eq!(buf.view_parts_at(2, 2), (&b"d"[..], &b"e"[..]));
+        // An interior window wholly before the wrap point stays contiguous.
+        assert_eq!(buf.view_parts_at(1, 2), (&b"cd"[..], &b""[..]));
+
+        // The mutable variant writes through to the backing store.
+        {
+            let (head, _tail) = buf.view_parts_at_mut(0, 1);
+            head[0] = b'B';
+        }
+        assert_eq!(buf.view_parts_at(0, 1), (&b"B"[..], &b""[..]));
+    }
+
+    #[test]
+    fn consume_with_tests() {
+        let mut buf = CircleBuffer::new([0u8; 4]);
+        buf.extend(b"abcd");
+        let first = buf.consume_with(4, |data| {
+            assert_eq!(data, b"abcd");
+            (2, data[0])
+        });
+        assert_eq!(first, b'a');
+        assert_eq!(buf.view_nocopy(), b"cd");
+
+        // `max` caps the slice offered to the callback, and a zero-use report consumes nothing.
+        buf.consume_with(1, |data| {
+            assert_eq!(data, b"c");
+            (0, ())
+        });
+        assert_eq!(buf.len(), 2);
+
+        // After wrapping, only the leading contiguous piece is offered.
+        buf.extend(b"ef");
+        let seen = buf.consume_with(10, |data| (data.len(), data.len()));
+        assert_eq!(seen, 2);
+        assert_eq!(buf.view_nocopy(), b"ef");
+    }
 }